@@ -1,8 +1,104 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// Kind of version increment implied by a set of changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Bump {
+    Major,
+    Minor,
+    Patch,
+    None,
+}
+
+impl Bump {
+    /// Returns `true` if the bump calls for a new release.
+    pub fn is_release(&self) -> bool {
+        !matches!(self, Bump::None)
+    }
+}
+
+impl fmt::Display for Bump {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Bump::Major => "major",
+            Bump::Minor => "minor",
+            Bump::Patch => "patch",
+            Bump::None => "none",
+        };
+        f.write_str(s)
+    }
+}
+
+/// Error returned when parsing a [`Bump`] from a string fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseBumpError {
+    input: String,
+}
+
+impl fmt::Display for ParseBumpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid bump \"{}\": expected one of major, minor, patch, none",
+            self.input
+        )
+    }
+}
+
+impl std::error::Error for ParseBumpError {}
+
+impl FromStr for Bump {
+    type Err = ParseBumpError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "major" => Ok(Bump::Major),
+            "minor" => Ok(Bump::Minor),
+            "patch" => Ok(Bump::Patch),
+            "none" => Ok(Bump::None),
+            _ => Err(ParseBumpError {
+                input: s.to_string(),
+            }),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use super::*;
+
     #[test]
     fn it_works() {
         let result = 2 + 2;
         assert_eq!(result, 4);
     }
+
+    #[test]
+    fn bump_from_str_accepts_each_kind_case_insensitively() {
+        assert_eq!("major".parse::<Bump>(), Ok(Bump::Major));
+        assert_eq!("Minor".parse::<Bump>(), Ok(Bump::Minor));
+        assert_eq!("PATCH".parse::<Bump>(), Ok(Bump::Patch));
+        assert_eq!("none".parse::<Bump>(), Ok(Bump::None));
+    }
+
+    #[test]
+    fn bump_from_str_rejects_unknown_kind() {
+        let err = "huge".parse::<Bump>().unwrap_err();
+        assert!(err.to_string().contains("huge"));
+    }
+
+    #[test]
+    fn bump_display_round_trips_through_from_str() {
+        for bump in [Bump::Major, Bump::Minor, Bump::Patch, Bump::None] {
+            assert_eq!(bump.to_string().parse::<Bump>(), Ok(bump));
+        }
+    }
+
+    #[test]
+    fn bump_is_release_for_every_kind_but_none() {
+        assert!(Bump::Major.is_release());
+        assert!(Bump::Minor.is_release());
+        assert!(Bump::Patch.is_release());
+        assert!(!Bump::None.is_release());
+    }
 }